@@ -0,0 +1,144 @@
+use core::ops::DerefMut;
+use core::pin::Pin;
+
+use crate::LazyPinned;
+
+/// Extension trait that lifts [`LazyPinned`]'s pin-projecting methods to work
+/// through any pinned pointer, not just `Pin<&mut LazyPinned<T>>`.
+///
+/// This mirrors how [`Pin`] itself is generic over the wrapped pointer: the
+/// methods here are implemented for every `Pin<P>` where
+/// `P: DerefMut<Target = LazyPinned<T>>`, such as `Pin<Box<LazyPinned<T>>>` or
+/// `Pin<&mut Box<LazyPinned<T>>>`, so callers no longer need to first reborrow
+/// to `Pin<&mut LazyPinned<T>>` before projecting into the inner `T`.
+pub trait LazyPinnedExt<T> {
+    fn pin_project_or_insert(&mut self, v: T) -> Pin<&mut T>;
+
+    fn pin_project_or_insert_with(&mut self, f: impl FnOnce() -> T) -> Pin<&mut T>;
+
+    fn pin_project_or_try_insert_with<E>(
+        &mut self,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E>;
+
+    fn use_pin_or_insert(&mut self, use_pin: impl FnOnce(Pin<&mut T>), v: T) -> Pin<&mut T>;
+
+    fn use_pin_or_insert_with(
+        &mut self,
+        use_pin: impl FnOnce(Pin<&mut T>),
+        insert: impl FnOnce() -> T,
+    ) -> Pin<&mut T>;
+
+    fn use_pin_or_insert_with_data<Data>(
+        &mut self,
+        data: Data,
+        use_pin: impl FnOnce(Data, Pin<&mut T>),
+        insert: impl FnOnce(Data) -> T,
+    ) -> Pin<&mut T>;
+
+    fn use_pin_or_try_insert_with<E>(
+        &mut self,
+        use_pin: impl FnOnce(Pin<&mut T>),
+        insert: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E>;
+
+    fn use_pin_or_try_insert_with_data<Data, E>(
+        &mut self,
+        data: Data,
+        use_pin: impl FnOnce(Data, Pin<&mut T>),
+        insert: impl FnOnce(Data) -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E>;
+
+    fn set(&mut self, value: T);
+
+    fn clear(&mut self);
+
+    fn take(&mut self) -> Option<T>
+    where
+        T: Unpin;
+
+    fn replace(&mut self, value: T) -> Option<T>
+    where
+        T: Unpin;
+}
+
+impl<T, P> LazyPinnedExt<T> for Pin<P>
+where
+    P: DerefMut<Target = LazyPinned<T>>,
+{
+    fn pin_project_or_insert(&mut self, v: T) -> Pin<&mut T> {
+        self.as_mut().pin_project_or_insert(v)
+    }
+
+    fn pin_project_or_insert_with(&mut self, f: impl FnOnce() -> T) -> Pin<&mut T> {
+        self.as_mut().pin_project_or_insert_with(f)
+    }
+
+    fn pin_project_or_try_insert_with<E>(
+        &mut self,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        self.as_mut().pin_project_or_try_insert_with(f)
+    }
+
+    fn use_pin_or_insert(&mut self, use_pin: impl FnOnce(Pin<&mut T>), v: T) -> Pin<&mut T> {
+        self.as_mut().use_pin_or_insert(use_pin, v)
+    }
+
+    fn use_pin_or_insert_with(
+        &mut self,
+        use_pin: impl FnOnce(Pin<&mut T>),
+        insert: impl FnOnce() -> T,
+    ) -> Pin<&mut T> {
+        self.as_mut().use_pin_or_insert_with(use_pin, insert)
+    }
+
+    fn use_pin_or_insert_with_data<Data>(
+        &mut self,
+        data: Data,
+        use_pin: impl FnOnce(Data, Pin<&mut T>),
+        insert: impl FnOnce(Data) -> T,
+    ) -> Pin<&mut T> {
+        self.as_mut().use_pin_or_insert_with_data(data, use_pin, insert)
+    }
+
+    fn use_pin_or_try_insert_with<E>(
+        &mut self,
+        use_pin: impl FnOnce(Pin<&mut T>),
+        insert: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        self.as_mut().use_pin_or_try_insert_with(use_pin, insert)
+    }
+
+    fn use_pin_or_try_insert_with_data<Data, E>(
+        &mut self,
+        data: Data,
+        use_pin: impl FnOnce(Data, Pin<&mut T>),
+        insert: impl FnOnce(Data) -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        self.as_mut()
+            .use_pin_or_try_insert_with_data(data, use_pin, insert)
+    }
+
+    fn set(&mut self, value: T) {
+        self.as_mut().set(value);
+    }
+
+    fn clear(&mut self) {
+        self.as_mut().clear();
+    }
+
+    fn take(&mut self) -> Option<T>
+    where
+        T: Unpin,
+    {
+        self.as_mut().take()
+    }
+
+    fn replace(&mut self, value: T) -> Option<T>
+    where
+        T: Unpin,
+    {
+        self.as_mut().replace(value)
+    }
+}
@@ -0,0 +1,77 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use crate::LazyPinned;
+
+impl<F> LazyPinned<F> {
+    pub fn poll_or_init(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        init: impl FnOnce() -> F,
+    ) -> Poll<F::Output>
+    where
+        F: Future,
+    {
+        let fut = self.as_mut().pin_project_or_insert_with(init);
+        let poll = fut.poll(cx);
+
+        if poll.is_ready() {
+            self.clear();
+        }
+
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::pin::pin;
+    use core::task::Waker;
+
+    struct CountingFuture {
+        polls_until_ready: usize,
+        polls: usize,
+    }
+
+    impl Future for CountingFuture {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<()> {
+            self.polls += 1;
+            if self.polls >= self.polls_until_ready {
+                Poll::Ready(())
+            } else {
+                Poll::Pending
+            }
+        }
+    }
+
+    #[test]
+    fn poll_or_init_constructs_once_and_clears_on_ready() {
+        let init_calls = Cell::new(0);
+        let mut cx = Context::from_waker(Waker::noop());
+
+        let mut slot: Pin<&mut LazyPinned<CountingFuture>> = pin!(LazyPinned::default());
+        let init = || {
+            init_calls.set(init_calls.get() + 1);
+            CountingFuture {
+                polls_until_ready: 3,
+                polls: 0,
+            }
+        };
+
+        assert_eq!(slot.as_mut().poll_or_init(&mut cx, init), Poll::Pending);
+        assert_eq!(init_calls.get(), 1);
+        assert!(slot.as_mut().as_pin_mut().is_some());
+
+        assert_eq!(slot.as_mut().poll_or_init(&mut cx, init), Poll::Pending);
+        assert_eq!(init_calls.get(), 1);
+
+        assert_eq!(slot.as_mut().poll_or_init(&mut cx, init), Poll::Ready(()));
+        assert_eq!(init_calls.get(), 1);
+        assert!(slot.as_mut().as_pin_mut().is_none());
+    }
+}
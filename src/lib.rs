@@ -5,6 +5,11 @@
 
 use core::pin::Pin;
 
+mod ext;
+mod future;
+
+pub use ext::LazyPinnedExt;
+
 /// Pinned data which can be lazily initialized.
 ///
 /// ## [`LazyPinned<T>`] vs. [`Option<T>`]
@@ -72,6 +77,37 @@ impl<T> LazyPinned<T> {
         unsafe { Pin::new_unchecked(x) }
     }
 
+    #[must_use]
+    pub fn project_pin_ref<U>(
+        self: Pin<&Self>,
+        f: impl FnOnce(Pin<&T>) -> Pin<&U>,
+    ) -> Option<Pin<&U>> {
+        self.as_pin_ref().map(f)
+    }
+
+    #[must_use]
+    pub fn project_pin_mut<U>(
+        self: Pin<&mut Self>,
+        f: impl FnOnce(Pin<&mut T>) -> Pin<&mut U>,
+    ) -> Option<Pin<&mut U>> {
+        self.as_pin_mut().map(f)
+    }
+
+    pub fn pin_project_or_try_insert_with<E>(
+        self: Pin<&mut Self>,
+        f: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        // SAFETY: `get_unchecked_mut` is never used to move the `Some(T)` inside `self`.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+
+        if this.0.is_none() {
+            this.0 = Some(f()?);
+        }
+
+        // SAFETY: the inner value is guaranteed to be pinned because it comes from `self` which is pinned.
+        Ok(unsafe { Pin::new_unchecked(this.0.as_mut().unwrap()) })
+    }
+
     pub fn use_pin_or_insert(
         self: Pin<&mut Self>,
         use_pin: impl FnOnce(Pin<&mut T>),
@@ -104,6 +140,44 @@ impl<T> LazyPinned<T> {
         }
     }
 
+    pub fn use_pin_or_try_insert_with<E>(
+        self: Pin<&mut Self>,
+        use_pin: impl FnOnce(Pin<&mut T>),
+        insert: impl FnOnce() -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        // SAFETY: `get_unchecked_mut` is never used to move the `Some(T)` inside `self`.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+
+        match &mut this.0 {
+            Some(x) => {
+                // SAFETY: `x` is guaranteed to be pinned because it comes from `self` which is pinned.
+                let mut x = unsafe { Pin::new_unchecked(x) };
+                use_pin(x.as_mut());
+                Ok(x)
+            }
+            this @ None => {
+                let x = this.insert(insert()?);
+
+                // SAFETY: `x` is guaranteed to be pinned because it comes from `self` which is pinned.
+                Ok(unsafe { Pin::new_unchecked(x) })
+            }
+        }
+    }
+
+    pub fn set(self: Pin<&mut Self>, value: T) {
+        // SAFETY: `get_unchecked_mut` is used to overwrite the `Option<T>` in place.
+        // The old `T`, if any, is dropped in place by the assignment below without
+        // ever being moved, so the pin drop guarantee is upheld.
+        unsafe { Pin::get_unchecked_mut(self).0 = Some(value) };
+    }
+
+    pub fn clear(self: Pin<&mut Self>) {
+        // SAFETY: `get_unchecked_mut` is used to overwrite the `Option<T>` in place.
+        // The old `T`, if any, is dropped in place by the assignment below without
+        // ever being moved, so the pin drop guarantee is upheld.
+        unsafe { Pin::get_unchecked_mut(self).0 = None };
+    }
+
     pub fn use_pin_or_insert_with_data<Data>(
         self: Pin<&mut Self>,
         data: Data,
@@ -128,4 +202,113 @@ impl<T> LazyPinned<T> {
             }
         }
     }
+
+    pub fn use_pin_or_try_insert_with_data<Data, E>(
+        self: Pin<&mut Self>,
+        data: Data,
+        use_pin: impl FnOnce(Data, Pin<&mut T>),
+        insert: impl FnOnce(Data) -> Result<T, E>,
+    ) -> Result<Pin<&mut T>, E> {
+        // SAFETY: `get_unchecked_mut` is never used to move the `Some(T)` inside `self`.
+        let this = unsafe { Pin::get_unchecked_mut(self) };
+
+        match &mut this.0 {
+            Some(x) => {
+                // SAFETY: `x` is guaranteed to be pinned because it comes from `self` which is pinned.
+                let mut x = unsafe { Pin::new_unchecked(x) };
+                use_pin(data, x.as_mut());
+                Ok(x)
+            }
+            this @ None => {
+                let x = this.insert(insert(data)?);
+
+                // SAFETY: `x` is guaranteed to be pinned because it comes from `self` which is pinned.
+                Ok(unsafe { Pin::new_unchecked(x) })
+            }
+        }
+    }
+}
+
+/// These methods require `T: Unpin`, since moving the inner value out of a
+/// pinned `Option` would otherwise violate the pin drop guarantee.
+///
+/// ```compile_fail
+/// use core::marker::PhantomPinned;
+/// use core::pin::Pin;
+/// use lazy_pinned::LazyPinned;
+///
+/// let mut slot = LazyPinned(Some(PhantomPinned));
+/// let slot = unsafe { Pin::new_unchecked(&mut slot) };
+/// slot.take(); // `PhantomPinned` is `!Unpin`, so this does not compile.
+/// ```
+impl<T: Unpin> LazyPinned<T> {
+    pub fn take(self: Pin<&mut Self>) -> Option<T> {
+        // SAFETY: `T: Unpin`, so moving the inner value out of the pinned `Option`
+        // does not violate the pin drop guarantee.
+        unsafe { Pin::get_unchecked_mut(self).0.take() }
+    }
+
+    pub fn replace(self: Pin<&mut Self>, value: T) -> Option<T> {
+        // SAFETY: `T: Unpin`, so moving the old inner value out of the pinned
+        // `Option` does not violate the pin drop guarantee.
+        unsafe { Pin::get_unchecked_mut(self).0.replace(value) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::cell::Cell;
+    use core::pin::pin;
+
+    struct DropCounter<'a>(&'a Cell<usize>);
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            self.0.set(self.0.get() + 1);
+        }
+    }
+
+    #[test]
+    fn clear_drops_inner_value_in_place() {
+        let count = Cell::new(0);
+        let mut slot: Pin<&mut LazyPinned<DropCounter<'_>>> = pin!(LazyPinned::default());
+
+        slot.as_mut().pin_project_or_insert(DropCounter(&count));
+        assert_eq!(count.get(), 0);
+
+        slot.as_mut().clear();
+        assert_eq!(count.get(), 1);
+        assert!(slot.as_mut().as_pin_mut().is_none());
+    }
+
+    #[test]
+    fn set_drops_old_inner_value_in_place() {
+        let count = Cell::new(0);
+        let mut slot: Pin<&mut LazyPinned<DropCounter<'_>>> = pin!(LazyPinned::default());
+
+        slot.as_mut().pin_project_or_insert(DropCounter(&count));
+        assert_eq!(count.get(), 0);
+
+        slot.as_mut().set(DropCounter(&count));
+        assert_eq!(count.get(), 1);
+        assert!(slot.as_mut().as_pin_mut().is_some());
+    }
+
+    #[test]
+    fn try_insert_leaves_slot_none_on_err() {
+        let mut slot: Pin<&mut LazyPinned<i32>> = pin!(LazyPinned::default());
+
+        let result = slot
+            .as_mut()
+            .pin_project_or_try_insert_with(|| Err::<i32, _>("boom"));
+        assert_eq!(result.err(), Some("boom"));
+        assert!(slot.as_mut().as_pin_mut().is_none());
+
+        let result = slot
+            .as_mut()
+            .pin_project_or_try_insert_with(|| Ok::<_, &str>(42));
+        assert_eq!(*result.unwrap(), 42);
+        assert_eq!(slot.as_mut().as_pin_mut().map(|x| *x), Some(42));
+    }
 }